@@ -0,0 +1,140 @@
+//! CSV backtest mode: replay a Kelly staking strategy over historical bets.
+//!
+//! Reads rows of either `odds,win_rate,outcome` (standard mode) or
+//! `price,your_prob,resolved` (Polymarket mode), computes the Kelly fraction
+//! for each row with the existing calculators, and walks the strategy
+//! forward while compounding a starting capital.
+
+use crate::{kelly_criterion, kelly_polymarket};
+
+/// Which column layout the CSV rows use.
+pub enum Mode {
+    Standard,
+    Polymarket,
+}
+
+/// One row of backtest history, already reduced to what replay needs.
+struct BacktestRow {
+    kelly_fraction: f64,
+    decimal_odds: f64,
+    won: bool,
+}
+
+/// Summary of a replayed backtest.
+pub struct BacktestSummary {
+    pub final_capital: f64,
+    pub total_return: f64,
+    pub bets_taken: u32,
+    pub bets_skipped: u32,
+    pub win_rate_realized: f64,
+    pub max_drawdown: f64,
+    pub geometric_mean_growth: f64,
+}
+
+/// Replay `csv` against a starting `capital`, staking the current bankroll
+/// times `kelly_fraction * fraction_multiplier` on each row whose Kelly
+/// fraction is positive, and compounding the result.
+pub fn run(csv: &str, mode: Mode, capital: f64, fraction_multiplier: f64) -> Result<BacktestSummary, String> {
+    let rows = parse_rows(csv, mode)?;
+    if rows.is_empty() {
+        return Err("no data rows found in CSV".to_string());
+    }
+
+    let mut bankroll = capital;
+    let mut peak = capital;
+    let mut max_drawdown = 0.0_f64;
+    let mut bets_taken = 0u32;
+    let mut bets_skipped = 0u32;
+    let mut wins = 0u32;
+    let mut growth_log_sum = 0.0_f64;
+
+    for row in &rows {
+        let f = row.kelly_fraction * fraction_multiplier;
+        if f <= 0.0 {
+            bets_skipped += 1;
+            continue;
+        }
+
+        let stake = bankroll * f;
+        let b = row.decimal_odds - 1.0;
+        let before = bankroll;
+
+        if row.won {
+            bankroll += stake * b;
+            wins += 1;
+        } else {
+            bankroll -= stake;
+        }
+
+        bets_taken += 1;
+        growth_log_sum += (bankroll / before).ln();
+        peak = peak.max(bankroll);
+        max_drawdown = max_drawdown.max(1.0 - bankroll / peak);
+    }
+
+    let total_return = bankroll / capital - 1.0;
+    let win_rate_realized = if bets_taken > 0 { wins as f64 / bets_taken as f64 } else { 0.0 };
+    let geometric_mean_growth = if bets_taken > 0 {
+        (growth_log_sum / bets_taken as f64).exp() - 1.0
+    } else {
+        0.0
+    };
+
+    Ok(BacktestSummary {
+        final_capital: bankroll,
+        total_return,
+        bets_taken,
+        bets_skipped,
+        win_rate_realized,
+        max_drawdown,
+        geometric_mean_growth,
+    })
+}
+
+/// Parse CSV rows, skipping a header row if the first column isn't numeric.
+fn parse_rows(csv: &str, mode: Mode) -> Result<Vec<BacktestRow>, String> {
+    let mut rows = Vec::new();
+
+    for (i, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let cols: Vec<&str> = line.split(',').map(str::trim).collect();
+        if cols.len() != 3 {
+            return Err(format!("line {}: expected 3 columns, got {}", i + 1, cols.len()));
+        }
+        if i == 0 && cols[0].parse::<f64>().is_err() {
+            continue;
+        }
+
+        let a: f64 = cols[0]
+            .parse()
+            .map_err(|_| format!("line {}: invalid number '{}'", i + 1, cols[0]))?;
+        let b: f64 = cols[1]
+            .parse()
+            .map_err(|_| format!("line {}: invalid number '{}'", i + 1, cols[1]))?;
+        let won = match cols[2].to_lowercase().as_str() {
+            "win" | "won" | "1" | "true" => true,
+            "loss" | "lost" | "0" | "false" => false,
+            other => return Err(format!("line {}: invalid outcome '{}'", i + 1, other)),
+        };
+
+        let (decimal_odds, kelly_fraction) = match mode {
+            Mode::Standard => {
+                let result = kelly_criterion(a, b / 100.0);
+                (a, result.optimal_fraction)
+            }
+            Mode::Polymarket => {
+                let price = a / 100.0;
+                let result = kelly_polymarket(price, b / 100.0);
+                (1.0 / price, result.optimal_fraction)
+            }
+        };
+
+        rows.push(BacktestRow { kelly_fraction, decimal_odds, won });
+    }
+
+    Ok(rows)
+}
@@ -4,6 +4,19 @@
 
 use std::io::{self, Write};
 
+mod backtest;
+mod money;
+mod multi_kelly;
+mod odds;
+mod simulate;
+mod tick;
+
+use backtest::BacktestSummary;
+use money::CurrencyInfo;
+use multi_kelly::{MultiKellyResult, Outcome};
+use simulate::SimulationSummary;
+use tick::Tick;
+
 /// Language setting
 #[derive(Clone, Copy, PartialEq)]
 enum Language {
@@ -12,9 +25,9 @@ enum Language {
 }
 
 /// Kelly calculation result
-struct KellyResult {
+pub(crate) struct KellyResult {
     /// Optimal fraction (0-1)
-    optimal_fraction: f64,
+    pub(crate) optimal_fraction: f64,
     /// Positive expected value
     positive_ev: bool,
     /// Expected value
@@ -26,7 +39,7 @@ struct KellyResult {
 /// # Arguments
 /// * `odds` - Decimal odds (e.g., 2.0 means even money, net odds = 1)
 /// * `win_rate` - Probability of winning (0-1)
-fn kelly_criterion(odds: f64, win_rate: f64) -> KellyResult {
+pub(crate) fn kelly_criterion(odds: f64, win_rate: f64) -> KellyResult {
     let b = odds - 1.0;
     let p = win_rate;
     let q = 1.0 - p;
@@ -46,7 +59,7 @@ fn kelly_criterion(odds: f64, win_rate: f64) -> KellyResult {
 /// # Arguments
 /// * `market_price` - Market price (0-1), e.g., 0.60 for 60c
 /// * `your_probability` - Your estimated true probability (0-1)
-fn kelly_polymarket(market_price: f64, your_probability: f64) -> KellyResult {
+pub(crate) fn kelly_polymarket(market_price: f64, your_probability: f64) -> KellyResult {
     let p_market = market_price;
     let p_your = your_probability;
 
@@ -107,8 +120,33 @@ fn print_title_polymarket(lang: Language) {
     println!();
 }
 
+/// Format one stake amount, appending the tick-rounded, actually-placeable
+/// amount alongside the raw Kelly amount when a tick policy is given.
+fn stake_line(amount: f64, currency: Option<&CurrencyInfo>, tick: Option<&Tick>, lang: Language) -> String {
+    let raw = money::format_money(amount, currency);
+    match tick {
+        Some(t) => {
+            let rounded = money::format_money(t.round(amount), currency);
+            match lang {
+                Language::English => format!("{} (rounded: {})", raw, rounded),
+                Language::Chinese => format!("{} (取整: {})", raw, rounded),
+            }
+        }
+        None => raw,
+    }
+}
+
+/// Snap a Polymarket price in cents (0-100) to the venue's tradable price
+/// increment, if a price-tick policy was given.
+fn round_price(price_cents: f64, price_tick: Option<&Tick>) -> f64 {
+    match price_tick {
+        Some(pt) => pt.round(price_cents),
+        None => price_cents,
+    }
+}
+
 /// Print result (standard mode)
-fn print_result(odds: f64, win_rate: f64, result: &KellyResult, capital: Option<f64>, lang: Language) {
+fn print_result(odds: f64, win_rate: f64, result: &KellyResult, capital: Option<f64>, lang: Language, currency: Option<&CurrencyInfo>, tick: Option<&Tick>) {
     println!();
     separator();
     match lang {
@@ -144,11 +182,11 @@ fn print_result(odds: f64, win_rate: f64, result: &KellyResult, capital: Option<
             println!();
 
             if let Some(cap) = capital {
-                println!("  Position based on capital {:.2}:", cap);
+                println!("  Position based on capital {}:", money::format_money(cap, currency));
                 if result.optimal_fraction > 0.0 {
-                    println!("    ├─ Full Kelly: {:.2}", cap * result.optimal_fraction);
-                    println!("    ├─ Half Kelly: {:.2}", cap * result.optimal_fraction * 0.5);
-                    println!("    └─ Quarter Kelly: {:.2}", cap * result.optimal_fraction * 0.25);
+                    println!("    ├─ Full Kelly: {}", stake_line(cap * result.optimal_fraction, currency, tick, lang));
+                    println!("    ├─ Half Kelly: {}", stake_line(cap * result.optimal_fraction * 0.5, currency, tick, lang));
+                    println!("    └─ Quarter Kelly: {}", stake_line(cap * result.optimal_fraction * 0.25, currency, tick, lang));
                 } else {
                     println!("    └─ Recommendation: No bet");
                 }
@@ -180,11 +218,11 @@ fn print_result(odds: f64, win_rate: f64, result: &KellyResult, capital: Option<
             println!();
 
             if let Some(cap) = capital {
-                println!("  基于本金 {:.2} 的投注金额:", cap);
+                println!("  基于本金 {} 的投注金额:", money::format_money(cap, currency));
                 if result.optimal_fraction > 0.0 {
-                    println!("    ├─ 全凯利: {:.2}", cap * result.optimal_fraction);
-                    println!("    ├─ 半凯利: {:.2}", cap * result.optimal_fraction * 0.5);
-                    println!("    └─ 1/4凯利: {:.2}", cap * result.optimal_fraction * 0.25);
+                    println!("    ├─ 全凯利: {}", stake_line(cap * result.optimal_fraction, currency, tick, lang));
+                    println!("    ├─ 半凯利: {}", stake_line(cap * result.optimal_fraction * 0.5, currency, tick, lang));
+                    println!("    └─ 1/4凯利: {}", stake_line(cap * result.optimal_fraction * 0.25, currency, tick, lang));
                 } else {
                     println!("    └─ 建议: 不下注");
                 }
@@ -197,7 +235,7 @@ fn print_result(odds: f64, win_rate: f64, result: &KellyResult, capital: Option<
 }
 
 /// Print Polymarket result
-fn print_result_polymarket(market_price: f64, your_probability: f64, result: &KellyResult, capital: Option<f64>, lang: Language) {
+fn print_result_polymarket(market_price: f64, your_probability: f64, result: &KellyResult, capital: Option<f64>, lang: Language, currency: Option<&CurrencyInfo>, tick: Option<&Tick>) {
     println!();
     separator();
     match lang {
@@ -233,11 +271,11 @@ fn print_result_polymarket(market_price: f64, your_probability: f64, result: &Ke
             println!();
 
             if let Some(cap) = capital {
-                println!("  Position based on capital {:.2}:", cap);
+                println!("  Position based on capital {}:", money::format_money(cap, currency));
                 if result.optimal_fraction > 0.0 {
-                    println!("    ├─ Full Kelly: {:.2}", cap * result.optimal_fraction);
-                    println!("    ├─ Half Kelly: {:.2}", cap * result.optimal_fraction * 0.5);
-                    println!("    └─ Quarter Kelly: {:.2}", cap * result.optimal_fraction * 0.25);
+                    println!("    ├─ Full Kelly: {}", stake_line(cap * result.optimal_fraction, currency, tick, lang));
+                    println!("    ├─ Half Kelly: {}", stake_line(cap * result.optimal_fraction * 0.5, currency, tick, lang));
+                    println!("    └─ Quarter Kelly: {}", stake_line(cap * result.optimal_fraction * 0.25, currency, tick, lang));
                 } else {
                     println!("    └─ Recommendation: No bet");
                 }
@@ -269,11 +307,11 @@ fn print_result_polymarket(market_price: f64, your_probability: f64, result: &Ke
             println!();
 
             if let Some(cap) = capital {
-                println!("  基于本金 {:.2} 的投注金额:", cap);
+                println!("  基于本金 {} 的投注金额:", money::format_money(cap, currency));
                 if result.optimal_fraction > 0.0 {
-                    println!("    ├─ 全凯利: {:.2}", cap * result.optimal_fraction);
-                    println!("    ├─ 半凯利: {:.2}", cap * result.optimal_fraction * 0.5);
-                    println!("    └─ 1/4凯利: {:.2}", cap * result.optimal_fraction * 0.25);
+                    println!("    ├─ 全凯利: {}", stake_line(cap * result.optimal_fraction, currency, tick, lang));
+                    println!("    ├─ 半凯利: {}", stake_line(cap * result.optimal_fraction * 0.5, currency, tick, lang));
+                    println!("    └─ 1/4凯利: {}", stake_line(cap * result.optimal_fraction * 0.25, currency, tick, lang));
                 } else {
                     println!("    └─ 建议: 不下注");
                 }
@@ -285,14 +323,148 @@ fn print_result_polymarket(market_price: f64, your_probability: f64, result: &Ke
     separator();
 }
 
+/// Print the full/half/quarter Kelly Monte Carlo comparison table
+fn print_simulation_result(summaries: &[SimulationSummary], rounds: u32, lang: Language) {
+    println!();
+    separator();
+    match lang {
+        Language::English => println!("                  Monte Carlo Simulation ({} rounds)", rounds),
+        Language::Chinese => println!("                  蒙特卡洛模拟 ({} 轮)", rounds),
+    }
+    separator();
+    println!();
+
+    for summary in summaries {
+        match lang {
+            Language::English => {
+                println!("  {}:", summary.label);
+                println!("    ├─ Median final bankroll: {:.2}", summary.median_final);
+                println!("    ├─ 5th/95th percentile: {:.2} / {:.2}", summary.p5_final, summary.p95_final);
+                println!("    ├─ Mean growth rate/round: {}", format_pct(summary.mean_growth_rate));
+                println!("    ├─ Max drawdown: {}", format_pct(summary.max_drawdown));
+                println!("    └─ Risk of ruin: {}", format_pct(summary.risk_of_ruin));
+            }
+            Language::Chinese => {
+                println!("  {}:", summary.label);
+                println!("    ├─ 期末本金中位数: {:.2}", summary.median_final);
+                println!("    ├─ 5%/95% 分位数: {:.2} / {:.2}", summary.p5_final, summary.p95_final);
+                println!("    ├─ 平均每轮增长率: {}", format_pct(summary.mean_growth_rate));
+                println!("    ├─ 最大回撤: {}", format_pct(summary.max_drawdown));
+                println!("    └─ 爆仓概率: {}", format_pct(summary.risk_of_ruin));
+            }
+        }
+        println!();
+    }
+
+    separator();
+}
+
+/// Print the per-outcome stake table for a multi-outcome market
+fn print_multi_kelly_result(result: &MultiKellyResult, lang: Language) {
+    println!();
+    separator();
+    match lang {
+        Language::English => println!("                Multi-Outcome Kelly Result"),
+        Language::Chinese => println!("                多结果凯利计算结果"),
+    }
+    separator();
+    println!();
+
+    match lang {
+        Language::English => {
+            println!("  Outcomes:");
+            for (i, stake) in result.stakes.iter().enumerate() {
+                let branch = if i + 1 == result.stakes.len() { "└─" } else { "├─" };
+                println!(
+                    "    {} #{}: price {} | your prob {} | odds {:.2} | stake {}",
+                    branch,
+                    i + 1,
+                    format_pct(stake.price),
+                    format_pct(stake.probability),
+                    stake.decimal_odds,
+                    format_pct(stake.fraction)
+                );
+            }
+            println!();
+            println!("  Analysis:");
+            println!("    ├─ Reserve rate: {}", format_pct(result.reserve_rate));
+            println!("    └─ Total exposure: {}", format_pct(result.total_exposure));
+        }
+        Language::Chinese => {
+            println!("  各结果:");
+            for (i, stake) in result.stakes.iter().enumerate() {
+                let branch = if i + 1 == result.stakes.len() { "└─" } else { "├─" };
+                println!(
+                    "    {} #{}: 价格 {} | 你的概率 {} | 赔率 {:.2} | 仓位 {}",
+                    branch,
+                    i + 1,
+                    format_pct(stake.price),
+                    format_pct(stake.probability),
+                    stake.decimal_odds,
+                    format_pct(stake.fraction)
+                );
+            }
+            println!();
+            println!("  分析:");
+            println!("    ├─ 储备率: {}", format_pct(result.reserve_rate));
+            println!("    └─ 总仓位: {}", format_pct(result.total_exposure));
+        }
+    }
+
+    println!();
+    separator();
+}
+
+/// Print the equity-curve summary from a CSV backtest run
+fn print_backtest_result(summary: &BacktestSummary, capital: f64, lang: Language, currency: Option<&CurrencyInfo>) {
+    println!();
+    separator();
+    match lang {
+        Language::English => println!("                       Backtest Result"),
+        Language::Chinese => println!("                       历史回测结果"),
+    }
+    separator();
+    println!();
+
+    match lang {
+        Language::English => {
+            println!("  Input:");
+            println!("    └─ Starting capital: {}", money::format_money(capital, currency));
+            println!();
+            println!("  Equity Curve:");
+            println!("    ├─ Final capital: {}", money::format_money(summary.final_capital, currency));
+            println!("    ├─ Total return: {}", format_pct(summary.total_return));
+            println!("    ├─ Bets taken / skipped: {} / {}", summary.bets_taken, summary.bets_skipped);
+            println!("    ├─ Realized win rate: {}", format_pct(summary.win_rate_realized));
+            println!("    ├─ Max drawdown: {}", format_pct(summary.max_drawdown));
+            println!("    └─ Geometric mean growth/bet: {}", format_pct(summary.geometric_mean_growth));
+        }
+        Language::Chinese => {
+            println!("  输入参数:");
+            println!("    └─ 初始本金: {}", money::format_money(capital, currency));
+            println!();
+            println!("  权益曲线:");
+            println!("    ├─ 期末本金: {}", money::format_money(summary.final_capital, currency));
+            println!("    ├─ 总收益率: {}", format_pct(summary.total_return));
+            println!("    ├─ 下注次数 / 跳过次数: {} / {}", summary.bets_taken, summary.bets_skipped);
+            println!("    ├─ 实际胜率: {}", format_pct(summary.win_rate_realized));
+            println!("    ├─ 最大回撤: {}", format_pct(summary.max_drawdown));
+            println!("    └─ 每注几何平均增长率: {}", format_pct(summary.geometric_mean_growth));
+        }
+    }
+
+    println!();
+    separator();
+}
+
 /// Interactive mode
-fn interactive(lang: Language) {
+fn interactive(lang: Language, currency: Option<&CurrencyInfo>, tick: Option<&Tick>) {
     print_title(lang);
 
     loop {
         let (prompt_odds, prompt_win_rate, prompt_capital, msg_quit, msg_odds_error, msg_win_error, msg_cap_error) = match lang {
             Language::English => (
-                "Enter odds (e.g., 2.0 for 1:1, 'q' to quit):",
+                "Enter odds (decimal 2.0, fractional 3/2, American +150, or 'q' to quit):",
                 "Enter win rate (0-100, e.g., 60 for 60%):",
                 "Enter capital (optional, press Enter to skip):",
                 "Goodbye!",
@@ -301,7 +473,7 @@ fn interactive(lang: Language) {
                 "Capital must be positive, skipped"
             ),
             Language::Chinese => (
-                "请输入赔率 (如 2.0 表示 1赔1，输入 q 退出):",
+                "请输入赔率 (小数 2.0，分数 3/2，美式 +150，输入 q 退出):",
                 "请输入胜率 (0-100，如 60 表示 60%):",
                 "请输入本金 (可选，直接回车跳过):",
                 "再见！",
@@ -322,8 +494,8 @@ fn interactive(lang: Language) {
             break;
         }
 
-        let odds: f64 = match odds_input.trim().parse() {
-            Ok(n) if n > 1.0 => n,
+        let parsed_odds = match odds::parse(&odds_input) {
+            Ok(parsed) if parsed.decimal > 1.0 => parsed,
             Ok(_) => {
                 println!("✗ {}\n", msg_odds_error);
                 continue;
@@ -333,6 +505,11 @@ fn interactive(lang: Language) {
                 continue;
             }
         };
+        let odds = parsed_odds.decimal;
+        match lang {
+            Language::English => println!("  (implied probability: {:.1}%)", parsed_odds.implied_probability * 100.0),
+            Language::Chinese => println!("  (隐含概率: {:.1}%)", parsed_odds.implied_probability * 100.0),
+        }
 
         println!("{} ", prompt_win_rate);
         io::stdout().flush().unwrap();
@@ -373,13 +550,13 @@ fn interactive(lang: Language) {
         };
 
         let result = kelly_criterion(odds, win_rate);
-        print_result(odds, win_rate, &result, capital, lang);
+        print_result(odds, win_rate, &result, capital, lang, currency, tick);
         println!();
     }
 }
 
 /// Polymarket interactive mode
-fn interactive_polymarket(lang: Language) {
+fn interactive_polymarket(lang: Language, currency: Option<&CurrencyInfo>, tick: Option<&Tick>, price_tick: Option<&Tick>) {
     print_title_polymarket(lang);
 
     loop {
@@ -416,7 +593,10 @@ fn interactive_polymarket(lang: Language) {
         }
 
         let market_price: f64 = match price_input.trim().parse::<f64>() {
-            Ok(n) if n > 0.0 && n <= 100.0 => n / 100.0,
+            Ok(n) if n > 0.0 && n <= 100.0 => match price_tick {
+                Some(pt) => pt.round(n) / 100.0,
+                None => n / 100.0,
+            },
             Ok(_) => {
                 println!("✗ {}\n", msg_price_error);
                 continue;
@@ -464,21 +644,193 @@ fn interactive_polymarket(lang: Language) {
         };
 
         let result = kelly_polymarket(market_price, your_probability);
-        print_result_polymarket(market_price, your_probability, &result, capital, lang);
+        print_result_polymarket(market_price, your_probability, &result, capital, lang, currency, tick);
+        println!();
+    }
+}
+
+/// Simulation interactive mode
+fn interactive_simulate(lang: Language) {
+    print_title(lang);
+
+    loop {
+        let (prompt_odds, prompt_win_rate, prompt_capital, prompt_rounds, msg_quit) = match lang {
+            Language::English => (
+                "Enter odds (decimal 2.0, fractional 3/2, American +150, or 'q' to quit):",
+                "Enter win rate (0-100, e.g., 60 for 60%):",
+                "Enter capital:",
+                "Enter number of rounds to simulate:",
+                "Goodbye!",
+            ),
+            Language::Chinese => (
+                "请输入赔率 (小数 2.0，分数 3/2，美式 +150，输入 q 退出):",
+                "请输入胜率 (0-100，如 60 表示 60%):",
+                "请输入本金:",
+                "请输入模拟轮数:",
+                "再见！",
+            ),
+        };
+
+        print!("{} ", prompt_odds);
+        io::stdout().flush().unwrap();
+        let mut odds_input = String::new();
+        io::stdin().read_line(&mut odds_input).unwrap();
+
+        if odds_input.trim().to_lowercase() == "q" {
+            println!("{}", msg_quit);
+            break;
+        }
+
+        let odds = match odds::parse(&odds_input) {
+            Ok(parsed) if parsed.decimal > 1.0 => parsed.decimal,
+            _ => {
+                println!("✗ Invalid input\n");
+                continue;
+            }
+        };
+
+        print!("{} ", prompt_win_rate);
+        io::stdout().flush().unwrap();
+        let mut win_rate_input = String::new();
+        io::stdin().read_line(&mut win_rate_input).unwrap();
+        let win_rate = match win_rate_input.trim().parse::<f64>() {
+            Ok(n) if (0.0..=100.0).contains(&n) => n / 100.0,
+            _ => {
+                println!("✗ Invalid input\n");
+                continue;
+            }
+        };
+
+        print!("{} ", prompt_capital);
+        io::stdout().flush().unwrap();
+        let mut capital_input = String::new();
+        io::stdin().read_line(&mut capital_input).unwrap();
+        let capital: f64 = match capital_input.trim().parse() {
+            Ok(n) if n > 0.0 => n,
+            _ => {
+                println!("✗ Invalid input\n");
+                continue;
+            }
+        };
+
+        print!("{} ", prompt_rounds);
+        io::stdout().flush().unwrap();
+        let mut rounds_input = String::new();
+        io::stdin().read_line(&mut rounds_input).unwrap();
+        let rounds: u32 = match rounds_input.trim().parse() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                println!("✗ Invalid input\n");
+                continue;
+            }
+        };
+
+        cli_mode_simulate(odds, win_rate, capital, rounds, lang);
         println!();
     }
 }
 
+/// Multi-outcome interactive mode
+fn interactive_multi(lang: Language) {
+    print_title(lang);
+
+    let (prompt_price, prompt_prob, msg_done, msg_invalid) = match lang {
+        Language::English => (
+            "Enter outcome price (0-100, blank line when done):",
+            "Enter your probability for that outcome (0-100):",
+            "Enter an empty price to finish adding outcomes.",
+            "✗ Invalid input\n",
+        ),
+        Language::Chinese => (
+            "请输入该结果的价格 (0-100，输入空行结束):",
+            "请输入你对该结果的概率 (0-100):",
+            "输入空价格以结束添加结果。",
+            "✗ 输入无效\n",
+        ),
+    };
+
+    println!("{}", msg_done);
+    let mut outcomes = Vec::new();
+
+    loop {
+        print!("{} ", prompt_price);
+        io::stdout().flush().unwrap();
+        let mut price_input = String::new();
+        io::stdin().read_line(&mut price_input).unwrap();
+        if price_input.trim().is_empty() {
+            break;
+        }
+
+        let price: f64 = match price_input.trim().parse::<f64>() {
+            Ok(n) if n > 0.0 && n <= 100.0 => n / 100.0,
+            _ => {
+                print!("{}", msg_invalid);
+                continue;
+            }
+        };
+
+        print!("{} ", prompt_prob);
+        io::stdout().flush().unwrap();
+        let mut prob_input = String::new();
+        io::stdin().read_line(&mut prob_input).unwrap();
+        let probability: f64 = match prob_input.trim().parse::<f64>() {
+            Ok(n) if (0.0..=100.0).contains(&n) => n / 100.0,
+            _ => {
+                print!("{}", msg_invalid);
+                continue;
+            }
+        };
+
+        outcomes.push(Outcome { price, probability });
+    }
+
+    cli_mode_multi(outcomes, lang);
+}
+
 /// CLI mode
-fn cli_mode(odds: f64, win_rate: f64, capital: Option<f64>, lang: Language) {
+fn cli_mode(odds: f64, win_rate: f64, capital: Option<f64>, lang: Language, currency: Option<&CurrencyInfo>, tick: Option<&Tick>) {
     let result = kelly_criterion(odds, win_rate);
-    print_result(odds, win_rate, &result, capital, lang);
+    print_result(odds, win_rate, &result, capital, lang, currency, tick);
 }
 
 /// Polymarket CLI mode
-fn cli_mode_polymarket(market_price: f64, your_probability: f64, capital: Option<f64>, lang: Language) {
+fn cli_mode_polymarket(market_price: f64, your_probability: f64, capital: Option<f64>, lang: Language, currency: Option<&CurrencyInfo>, tick: Option<&Tick>) {
     let result = kelly_polymarket(market_price, your_probability);
-    print_result_polymarket(market_price, your_probability, &result, capital, lang);
+    print_result_polymarket(market_price, your_probability, &result, capital, lang, currency, tick);
+}
+
+/// Monte Carlo simulation CLI mode
+fn cli_mode_simulate(odds: f64, win_rate: f64, capital: f64, rounds: u32, lang: Language) {
+    let result = kelly_criterion(odds, win_rate);
+    let summaries = simulate::simulate_all(odds, win_rate, capital, rounds, result.optimal_fraction.max(0.0));
+    print_simulation_result(&summaries, rounds, lang);
+}
+
+/// Multi-outcome Kelly CLI mode
+fn cli_mode_multi(outcomes: Vec<Outcome>, lang: Language) {
+    match multi_kelly::solve(&outcomes) {
+        Ok(result) => print_multi_kelly_result(&result, lang),
+        Err(e) => println!("✗ {}", e),
+    }
+}
+
+/// Parse a fraction-multiplier argument: "full"/"half"/"quarter" or a raw number
+fn parse_fraction_multiplier(input: &str) -> f64 {
+    match input.to_lowercase().as_str() {
+        "full" => 1.0,
+        "half" => 0.5,
+        "quarter" => 0.25,
+        _ => input.parse().expect("Fraction must be 'full', 'half', 'quarter', or a number"),
+    }
+}
+
+/// CSV backtest CLI mode
+fn cli_mode_backtest(path: &str, mode: backtest::Mode, capital: f64, fraction_multiplier: f64, lang: Language, currency: Option<&CurrencyInfo>) {
+    let csv = std::fs::read_to_string(path).expect("Could not read backtest CSV file");
+    match backtest::run(&csv, mode, capital, fraction_multiplier) {
+        Ok(summary) => print_backtest_result(&summary, capital, lang, currency),
+        Err(e) => println!("✗ {}", e),
+    }
 }
 
 /// Print usage
@@ -494,16 +846,39 @@ fn print_usage(lang: Language) {
             println!("  kelly -p <price> <prob>          # Polymarket CLI");
             println!("  kelly -p <price> <prob> <capital>");
             println!();
+            println!("  kelly -s                         # Simulation interactive");
+            println!("  kelly -s <odds> <win_rate> <capital> <rounds> # Monte Carlo simulation");
+            println!();
+            println!("  kelly -m                         # Multi-outcome interactive");
+            println!("  kelly -m <price> <prob> ...      # Multi-outcome CLI (repeated pairs)");
+            println!();
+            println!("  kelly -b <csv> <capital> [frac]  # CSV backtest (odds,win_rate,outcome rows)");
+            println!("  kelly -b -p <csv> <capital> [frac] # CSV backtest (price,your_prob,resolved rows)");
+            println!();
             println!("  -z, --zh                        # Chinese output");
+            println!("  -c, --currency <code>            # Format amounts as ISO currency (USD, EUR, GBP, JPY, CNY)");
+            println!("  -t, --tick <size|file>           # Round stakes to tradable increments (uniform size or boundary,value table file)");
+            println!("  --price-tick <size|file>          # Round Polymarket market price (-p mode) to tradable increments");
             println!();
             println!("Examples:");
             println!("  kelly 2.0 60                    # Odds 2.0, 60% win rate");
             println!("  kelly 2.0 60 10000              # With 10000 capital");
+            println!("  kelly 3/2 60                    # Fractional odds 3/2");
+            println!("  kelly +150 55                   # American moneyline +150");
             println!();
             println!("  kelly -p 60 75                  # Market 60c, you think 75%");
             println!("  kelly -p 60 75 1000             # With 1000 capital");
             println!();
+            println!("  kelly -s 2.0 60 10000 100        # Simulate 100 rounds at 10000 capital");
+            println!();
+            println!("  kelly -m 40 45 35 30 20 25       # Three-candidate market");
+            println!();
+            println!("  kelly -b history.csv 10000 half  # Half-Kelly backtest over history.csv");
+            println!();
             println!("  kelly -z 2.0 60                 # Chinese output");
+            println!("  kelly -c JPY 2.0 60 10000        # Yen-formatted capital");
+            println!("  kelly --tick 100 2.0 60 3333     # Round stakes to the nearest 100");
+            println!("  kelly --price-tick 1 -p 60 75    # Round the 60c market price to the nearest cent");
         }
         Language::Chinese => {
             println!("用法:");
@@ -515,20 +890,84 @@ fn print_usage(lang: Language) {
             println!("  kelly -p <价格> <概率>           # Polymarket 命令行");
             println!("  kelly -p <价格> <概率> <本金>");
             println!();
+            println!("  kelly -s                         # 模拟交互式");
+            println!("  kelly -s <赔率> <胜率> <本金> <轮数> # 蒙特卡洛模拟");
+            println!();
+            println!("  kelly -m                         # 多结果交互式");
+            println!("  kelly -m <价格> <概率> ...        # 多结果命令行 (重复的价格/概率对)");
+            println!();
+            println!("  kelly -b <csv> <本金> [比例]      # CSV 回测 (odds,win_rate,outcome 行)");
+            println!("  kelly -b -p <csv> <本金> [比例]   # CSV 回测 (price,your_prob,resolved 行)");
+            println!();
             println!("  -z, --zh                        # 中文输出");
+            println!("  -c, --currency <代码>            # 按 ISO 货币代码格式化金额 (USD, EUR, GBP, JPY, CNY)");
+            println!("  -t, --tick <数值|文件>           # 按交易所最小下注单位取整 (统一单位或 boundary,value 对照表文件)");
+            println!("  --price-tick <数值|文件>          # 按交易所最小价格单位取整 Polymarket 市场价格 (-p 模式)");
             println!();
             println!("示例:");
             println!("  kelly 2.0 60                    # 赔率2.0，胜率60%");
             println!("  kelly 2.0 60 10000              # 本金10000");
+            println!("  kelly 3/2 60                    # 分数赔率 3/2");
+            println!("  kelly +150 55                   # 美式赔率 +150");
             println!();
             println!("  kelly -p 60 75                  # 市场价格60c，你认为75%");
             println!("  kelly -p 60 75 1000             # 本金1000");
+            println!();
+            println!("  kelly -s 2.0 60 10000 100        # 模拟100轮，本金10000");
+            println!();
+            println!("  kelly -m 40 45 35 30 20 25       # 三候选人市场");
+            println!();
+            println!("  kelly -b history.csv 10000 half  # 对 history.csv 做半凯利回测");
+            println!();
+            println!("  kelly -c JPY 2.0 60 10000        # 以日元格式显示本金");
+            println!("  kelly --tick 100 2.0 60 3333     # 投注金额取整到最近的100");
+            println!("  kelly --price-tick 1 -p 60 75    # 将60c的市场价格取整到最近的1分");
         }
     }
 }
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let raw_args: Vec<String> = std::env::args().collect();
+
+    // Parse and strip the currency, stake-tick and price-tick flags; their
+    // values can be any token (a tick value may even be a file path), so
+    // they're stripped by position rather than by matching against a fixed
+    // set.
+    let mut currency: Option<&CurrencyInfo> = None;
+    let mut tick: Option<Tick> = None;
+    let mut price_tick: Option<Tick> = None;
+    let mut args: Vec<String> = Vec::with_capacity(raw_args.len());
+    let mut skip_next = false;
+    for (i, a) in raw_args.iter().enumerate() {
+        if skip_next {
+            skip_next = false;
+            continue;
+        }
+        if a == "-c" || a == "--currency" {
+            if let Some(code) = raw_args.get(i + 1) {
+                currency = money::lookup(code);
+            }
+            skip_next = true;
+            continue;
+        }
+        if a == "-t" || a == "--tick" {
+            if let Some(value) = raw_args.get(i + 1) {
+                tick = Some(Tick::parse(value).expect("Invalid tick argument"));
+            }
+            skip_next = true;
+            continue;
+        }
+        if a == "--price-tick" {
+            if let Some(value) = raw_args.get(i + 1) {
+                price_tick = Some(Tick::parse(value).expect("Invalid price-tick argument"));
+            }
+            skip_next = true;
+            continue;
+        }
+        args.push(a.clone());
+    }
+    let tick = tick.as_ref();
+    let price_tick = price_tick.as_ref();
 
     // Parse language flag
     let mut lang = Language::English;
@@ -540,23 +979,58 @@ fn main() {
     // Check for Polymarket mode
     let is_polymarket = args.iter().any(|a| a == "-p");
 
-    if is_polymarket {
-        let pm_args: Vec<&String> = args.iter().filter(|&a| {
+    // Check for Monte Carlo simulation mode
+    let is_simulate = args.iter().any(|a| a == "-s" || a == "--simulate");
+
+    // Check for multi-outcome mode
+    let is_multi = args.iter().any(|a| a == "-m" || a == "--multi");
+
+    // Check for CSV backtest mode (can be combined with -p for Polymarket-style columns)
+    let is_backtest = args.iter().any(|a| a == "-b" || a == "--backtest");
+
+    if is_backtest {
+        let bt_args: Vec<&String> = args.iter().skip(1).filter(|&a| {
+            a != "-b" && a != "--backtest" && a != "-p" && a != "-z" && a != "--zh"
+        }).collect();
+
+        let mode = if is_polymarket { backtest::Mode::Polymarket } else { backtest::Mode::Standard };
+
+        match bt_args.len() {
+            2 => {
+                let capital: f64 = bt_args[1].parse().expect("Capital must be a number");
+                cli_mode_backtest(bt_args[0], mode, capital, 1.0, lang, currency);
+            }
+            3 => {
+                let capital: f64 = bt_args[1].parse().expect("Capital must be a number");
+                let fraction_multiplier = parse_fraction_multiplier(bt_args[2]);
+                cli_mode_backtest(bt_args[0], mode, capital, fraction_multiplier, lang, currency);
+            }
+            _ => {
+                println!("✗ Invalid backtest mode arguments");
+                println!();
+                println!("Usage: kelly -b <csv_path> <capital> [full|half|quarter]");
+                println!("Example: kelly -b history.csv 10000 half");
+            }
+        }
+    } else if is_polymarket {
+        let pm_args: Vec<&String> = args.iter().skip(1).filter(|&a| {
             a != "-p" && a != "-z" && a != "--zh"
         }).collect();
 
         match pm_args.len() {
-            0 => interactive_polymarket(lang),
+            0 => interactive_polymarket(lang, currency, tick, price_tick),
             2 => {
-                let market_price: f64 = pm_args[0].parse::<f64>().expect("Market price must be a number") / 100.0;
+                let price_cents: f64 = pm_args[0].parse::<f64>().expect("Market price must be a number");
+                let market_price: f64 = round_price(price_cents, price_tick) / 100.0;
                 let your_prob: f64 = pm_args[1].parse::<f64>().expect("Your probability must be a number") / 100.0;
-                cli_mode_polymarket(market_price, your_prob, None, lang);
+                cli_mode_polymarket(market_price, your_prob, None, lang, currency, tick);
             }
             3 => {
-                let market_price: f64 = pm_args[0].parse::<f64>().expect("Market price must be a number") / 100.0;
+                let price_cents: f64 = pm_args[0].parse::<f64>().expect("Market price must be a number");
+                let market_price: f64 = round_price(price_cents, price_tick) / 100.0;
                 let your_prob: f64 = pm_args[1].parse::<f64>().expect("Your probability must be a number") / 100.0;
                 let capital: f64 = pm_args[2].parse().expect("Capital must be a number");
-                cli_mode_polymarket(market_price, your_prob, Some(capital), lang);
+                cli_mode_polymarket(market_price, your_prob, Some(capital), lang, currency, tick);
             }
             _ => {
                 println!("✗ Invalid Polymarket mode arguments");
@@ -565,6 +1039,49 @@ fn main() {
                 println!("Example: kelly -p 60 75    # Market 60c, you think 75%");
             }
         }
+    } else if is_simulate {
+        let sim_args: Vec<&String> = args.iter().skip(1).filter(|&a| {
+            a != "-s" && a != "--simulate" && a != "-z" && a != "--zh"
+        }).collect();
+
+        match sim_args.len() {
+            0 => interactive_simulate(lang),
+            4 => {
+                let odds = odds::parse(sim_args[0]).expect("Odds must be a valid odds notation").decimal;
+                let win_rate: f64 = sim_args[1].parse::<f64>().expect("Win rate must be a number") / 100.0;
+                let capital: f64 = sim_args[2].parse().expect("Capital must be a number");
+                let rounds: u32 = sim_args[3].parse().expect("Rounds must be a number");
+                cli_mode_simulate(odds, win_rate, capital, rounds, lang);
+            }
+            _ => {
+                println!("✗ Invalid simulation mode arguments");
+                println!();
+                println!("Usage: kelly -s <odds> <win_rate> <capital> <rounds>");
+                println!("Example: kelly -s 2.0 60 10000 100");
+            }
+        }
+    } else if is_multi {
+        let multi_args: Vec<&String> = args.iter().skip(1).filter(|&a| {
+            a != "-m" && a != "--multi" && a != "-z" && a != "--zh"
+        }).collect();
+
+        if multi_args.is_empty() {
+            interactive_multi(lang);
+        } else if multi_args.len().is_multiple_of(2) {
+            let outcomes: Vec<Outcome> = multi_args
+                .chunks(2)
+                .map(|pair| Outcome {
+                    price: pair[0].parse::<f64>().expect("Price must be a number") / 100.0,
+                    probability: pair[1].parse::<f64>().expect("Probability must be a number") / 100.0,
+                })
+                .collect();
+            cli_mode_multi(outcomes, lang);
+        } else {
+            println!("✗ Invalid multi-outcome mode arguments");
+            println!();
+            println!("Usage: kelly -m <price> <prob> [<price> <prob> ...]");
+            println!("Example: kelly -m 40 45 35 30 20 25");
+        }
     } else {
         // Filter out language flag
         let args: Vec<&String> = args.iter().filter(|&a| {
@@ -572,7 +1089,7 @@ fn main() {
         }).collect();
 
         match args.len() {
-            1 => interactive(lang),
+            1 => interactive(lang, currency, tick),
             2 => {
                 if args[1] == "-h" || args[1] == "--help" {
                     print_usage(lang);
@@ -582,15 +1099,15 @@ fn main() {
                 }
             }
             3 => {
-                let odds: f64 = args[1].parse().expect("Odds must be a number");
+                let odds = odds::parse(args[1]).expect("Odds must be a valid odds notation").decimal;
                 let win_rate: f64 = args[2].parse::<f64>().expect("Win rate must be a number") / 100.0;
-                cli_mode(odds, win_rate, None, lang);
+                cli_mode(odds, win_rate, None, lang, currency, tick);
             }
             4 => {
-                let odds: f64 = args[1].parse().expect("Odds must be a number");
+                let odds = odds::parse(args[1]).expect("Odds must be a valid odds notation").decimal;
                 let win_rate: f64 = args[2].parse::<f64>().expect("Win rate must be a number") / 100.0;
                 let capital: f64 = args[3].parse().expect("Capital must be a number");
-                cli_mode(odds, win_rate, Some(capital), lang);
+                cli_mode(odds, win_rate, Some(capital), lang, currency, tick);
             }
             _ => {
                 println!("✗ Too many arguments");
@@ -0,0 +1,76 @@
+//! Currency-aware formatting of monetary amounts.
+//!
+//! Plain `{:.2}` formatting is wrong once you leave USD-like currencies: JPY
+//! has no decimal places, and many locales use different thousands/decimal
+//! separators. This module formats an amount according to a currency's
+//! display conventions, with a unit-less fallback so default output is
+//! unchanged when no `--currency`/`-c` flag is given.
+
+/// Display conventions for a single currency.
+pub struct CurrencyInfo {
+    pub code: &'static str,
+    pub symbol: &'static str,
+    /// Whether the symbol goes before the amount (`$10.00`) or after (`10,00 €`)
+    pub symbol_before: bool,
+    pub decimals: usize,
+    pub decimal_separator: char,
+    pub group_separator: char,
+}
+
+const CURRENCIES: &[CurrencyInfo] = &[
+    CurrencyInfo { code: "USD", symbol: "$", symbol_before: true, decimals: 2, decimal_separator: '.', group_separator: ',' },
+    CurrencyInfo { code: "GBP", symbol: "£", symbol_before: true, decimals: 2, decimal_separator: '.', group_separator: ',' },
+    CurrencyInfo { code: "EUR", symbol: "€", symbol_before: false, decimals: 2, decimal_separator: ',', group_separator: '.' },
+    CurrencyInfo { code: "JPY", symbol: "¥", symbol_before: true, decimals: 0, decimal_separator: '.', group_separator: ',' },
+    CurrencyInfo { code: "CNY", symbol: "¥", symbol_before: true, decimals: 2, decimal_separator: '.', group_separator: ',' },
+];
+
+/// Look up currency display metadata by ISO code (case-insensitive).
+pub fn lookup(code: &str) -> Option<&'static CurrencyInfo> {
+    CURRENCIES.iter().find(|c| c.code.eq_ignore_ascii_case(code))
+}
+
+/// Format a monetary amount.
+///
+/// With `currency` given, applies its symbol placement, decimal digit count,
+/// and grouping/decimal separators. With `None`, falls back to the plain
+/// unit-less `{:.2}` formatting used before currency support existed.
+pub fn format_money(amount: f64, currency: Option<&CurrencyInfo>) -> String {
+    match currency {
+        None => format!("{:.2}", amount),
+        Some(c) => {
+            let grouped = group_digits(amount, c.decimals, c.group_separator, c.decimal_separator);
+            if c.symbol_before {
+                format!("{}{}", c.symbol, grouped)
+            } else {
+                format!("{} {}", grouped, c.symbol)
+            }
+        }
+    }
+}
+
+/// Render `amount` with `decimals` fractional digits, `group_sep` every three
+/// integer digits, and `decimal_sep` between the integer and fractional parts.
+fn group_digits(amount: f64, decimals: usize, group_sep: char, decimal_sep: char) -> String {
+    let formatted = format!("{:.*}", decimals, amount.abs());
+    let (int_part, frac_part) = match formatted.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut reversed_grouped = String::new();
+    for (i, ch) in int_part.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            reversed_grouped.push(group_sep);
+        }
+        reversed_grouped.push(ch);
+    }
+    let grouped_int: String = reversed_grouped.chars().rev().collect();
+
+    let sign = if amount < 0.0 { "-" } else { "" };
+
+    match frac_part {
+        Some(f) if !f.is_empty() => format!("{}{}{}{}", sign, grouped_int, decimal_sep, f),
+        _ => format!("{}{}", sign, grouped_int),
+    }
+}
@@ -0,0 +1,109 @@
+//! Multi-outcome Kelly staking for markets with several mutually exclusive
+//! outcomes (e.g. a multi-candidate Polymarket), using the
+//! Smoczynski-Tomkins algorithm.
+
+/// One outcome in a multi-outcome market.
+pub struct Outcome {
+    /// Market price (0-1), e.g. 0.40 for a 40c share
+    pub price: f64,
+    /// Your estimated true probability (0-1)
+    pub probability: f64,
+}
+
+/// Optimal stake fraction computed for one outcome.
+pub struct OutcomeStake {
+    pub price: f64,
+    pub probability: f64,
+    pub decimal_odds: f64,
+    /// Fraction of bankroll to stake on this outcome (0 if excluded)
+    pub fraction: f64,
+}
+
+/// Result of running the Smoczynski-Tomkins algorithm over a market.
+pub struct MultiKellyResult {
+    pub stakes: Vec<OutcomeStake>,
+    /// Reserve rate of the final candidate set
+    pub reserve_rate: f64,
+    /// Sum of all outcome stake fractions
+    pub total_exposure: f64,
+}
+
+/// Solve for the optimal simultaneous stake on each outcome.
+///
+/// Outcomes are sorted by expected return `o_i * p_i` descending, then grown
+/// into a candidate set `S` while the next outcome's expected return still
+/// beats the reserve rate of `S`. Outcomes outside the final `S` get a 0
+/// stake.
+pub fn solve(outcomes: &[Outcome]) -> Result<MultiKellyResult, String> {
+    if outcomes.is_empty() {
+        return Err("no outcomes given".to_string());
+    }
+
+    let sum_implied: f64 = outcomes.iter().map(|o| o.price).sum();
+    if sum_implied >= 1.0 {
+        return Err(format!(
+            "market offers no favorable subset: implied probabilities sum to {:.4} >= 1.0",
+            sum_implied
+        ));
+    }
+
+    let prob_sum: f64 = outcomes.iter().map(|o| o.probability).sum();
+    if (prob_sum - 1.0).abs() > 1e-6 {
+        return Err(format!(
+            "your probabilities must sum to 1.0, got {:.4}",
+            prob_sum
+        ));
+    }
+
+    let mut order: Vec<usize> = (0..outcomes.len()).collect();
+    order.sort_by(|&a, &b| {
+        let score_a = (1.0 / outcomes[a].price) * outcomes[a].probability;
+        let score_b = (1.0 / outcomes[b].price) * outcomes[b].probability;
+        score_b.partial_cmp(&score_a).unwrap()
+    });
+
+    let mut sum_p = 0.0;
+    let mut sum_inv_o = 0.0;
+    let mut reserve_rate = 1.0;
+    let mut included = vec![false; outcomes.len()];
+
+    for &idx in &order {
+        let o = 1.0 / outcomes[idx].price;
+        let p = outcomes[idx].probability;
+        if o * p > reserve_rate {
+            sum_p += p;
+            sum_inv_o += 1.0 / o;
+            reserve_rate = (1.0 - sum_p) / (1.0 - sum_inv_o);
+            included[idx] = true;
+        } else {
+            break;
+        }
+    }
+
+    let stakes: Vec<OutcomeStake> = outcomes
+        .iter()
+        .enumerate()
+        .map(|(i, o)| {
+            let decimal_odds = 1.0 / o.price;
+            let fraction = if included[i] {
+                (o.probability - reserve_rate / decimal_odds).max(0.0)
+            } else {
+                0.0
+            };
+            OutcomeStake {
+                price: o.price,
+                probability: o.probability,
+                decimal_odds,
+                fraction,
+            }
+        })
+        .collect();
+
+    let total_exposure = stakes.iter().map(|s| s.fraction).sum();
+
+    Ok(MultiKellyResult {
+        stakes,
+        reserve_rate,
+        total_exposure,
+    })
+}
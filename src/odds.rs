@@ -0,0 +1,126 @@
+//! Flexible odds-input parsing.
+//!
+//! Real bettors don't write decimal odds — they write fractional ("3/2"),
+//! American moneylines ("+150", "-120"), or plain-language probability
+//! phrasings ("3 in 5", "2 to 1 against", "pays 3 to 1"). This module turns
+//! any of those into the decimal odds the Kelly calculator needs, alongside
+//! the implied probability the notation itself expresses (for "X in Y"
+//! style inputs, that's a market read worth showing next to the bettor's
+//! own win-rate estimate).
+
+/// A parsed odds input, expressed both ways the calculator needs it.
+pub struct ParsedOdds {
+    /// Decimal odds (e.g., 2.0 means even money)
+    pub decimal: f64,
+    /// Implied probability (0-1)
+    pub implied_probability: f64,
+}
+
+/// Parse a bettor-facing odds string into decimal odds + implied probability.
+///
+/// Supported notations:
+/// - Decimal: "2.0"
+/// - Fractional: "3/2" (net payoff X for stake Y, decimal = 1 + X/Y)
+/// - American moneyline: "+150" (decimal = 1 + X/100), "-120" (decimal = 1 + 100/X)
+/// - "X in Y" (probability X/Y)
+/// - "X to Y against" (probability Y/(X+Y)), "X to Y in favor" (probability X/(X+Y))
+/// - "pays X to Y" (fair probability Y/(X+Y))
+pub fn parse(input: &str) -> Result<ParsedOdds, String> {
+    let s = input.trim();
+    if s.is_empty() {
+        return Err("odds input is empty".to_string());
+    }
+
+    let lower = s.to_lowercase();
+
+    if let Some(rest) = lower.strip_prefix("pays ") {
+        return parse_pays(rest);
+    }
+    if let Some(rest) = lower.strip_suffix(" against") {
+        return parse_to_ratio(rest, false);
+    }
+    if let Some(rest) = lower.strip_suffix(" in favor") {
+        return parse_to_ratio(rest, true);
+    }
+    if let Some(idx) = lower.find(" in ") {
+        return parse_in(&lower, idx);
+    }
+    if s.starts_with('+') || s.starts_with('-') {
+        return parse_american(s);
+    }
+    if s.contains('/') {
+        return parse_fractional(s);
+    }
+    parse_decimal(s)
+}
+
+fn parse_decimal(s: &str) -> Result<ParsedOdds, String> {
+    let decimal: f64 = s
+        .parse()
+        .map_err(|_| format!("invalid odds: '{}'", s))?;
+    if decimal <= 1.0 {
+        return Err(format!("decimal odds must be greater than 1.0, got {}", decimal));
+    }
+    Ok(ParsedOdds { decimal, implied_probability: 1.0 / decimal })
+}
+
+fn parse_fractional(s: &str) -> Result<ParsedOdds, String> {
+    let (x, y) = parse_two_numbers(s, "/").map_err(|_| format!("invalid fractional odds: '{}'", s))?;
+    let decimal = 1.0 + x / y;
+    Ok(ParsedOdds { decimal, implied_probability: 1.0 / decimal })
+}
+
+fn parse_american(s: &str) -> Result<ParsedOdds, String> {
+    let sign = &s[..1];
+    let magnitude: f64 = s[1..]
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid American odds: '{}'", s))?;
+    if magnitude <= 0.0 {
+        return Err(format!("American odds magnitude must be positive, got '{}'", s));
+    }
+    let decimal = match sign {
+        "+" => 1.0 + magnitude / 100.0,
+        "-" => 1.0 + 100.0 / magnitude,
+        _ => unreachable!("caller already checked the leading sign"),
+    };
+    Ok(ParsedOdds { decimal, implied_probability: 1.0 / decimal })
+}
+
+fn parse_in(s: &str, idx: usize) -> Result<ParsedOdds, String> {
+    let x_str = &s[..idx];
+    let y_str = &s[idx + " in ".len()..];
+    let x: f64 = x_str.trim().parse().map_err(|_| format!("invalid 'X in Y' odds: '{}'", s))?;
+    let y: f64 = y_str.trim().parse().map_err(|_| format!("invalid 'X in Y' odds: '{}'", s))?;
+    if x < 0.0 || y <= 0.0 || x > y {
+        return Err(format!("'X in Y' must have 0 <= X <= Y, got '{}'", s));
+    }
+    let implied_probability = x / y;
+    Ok(ParsedOdds { decimal: 1.0 / implied_probability, implied_probability })
+}
+
+fn parse_to_ratio(s: &str, in_favor: bool) -> Result<ParsedOdds, String> {
+    let (x, y) = parse_two_numbers(s, " to ").map_err(|_| format!("invalid 'X to Y' odds: '{}'", s))?;
+    let implied_probability = if in_favor { x / (x + y) } else { y / (x + y) };
+    Ok(ParsedOdds { decimal: 1.0 / implied_probability, implied_probability })
+}
+
+fn parse_pays(s: &str) -> Result<ParsedOdds, String> {
+    let (x, y) = parse_two_numbers(s, " to ").map_err(|_| format!("invalid 'pays X to Y' odds: '{}'", s))?;
+    let decimal = 1.0 + x / y;
+    Ok(ParsedOdds { decimal, implied_probability: 1.0 / decimal })
+}
+
+/// Split `s` on the first occurrence of `sep` into two positive numbers.
+fn parse_two_numbers(s: &str, sep: &str) -> Result<(f64, f64), String> {
+    let parts: Vec<&str> = s.splitn(2, sep).collect();
+    if parts.len() != 2 {
+        return Err(format!("expected '<X>{}<Y>', got '{}'", sep, s));
+    }
+    let x: f64 = parts[0].trim().parse().map_err(|_| format!("invalid number in '{}'", s))?;
+    let y: f64 = parts[1].trim().parse().map_err(|_| format!("invalid number in '{}'", s))?;
+    if x <= 0.0 || y <= 0.0 {
+        return Err(format!("values must be positive, got '{}'", s));
+    }
+    Ok((x, y))
+}
@@ -0,0 +1,152 @@
+//! Monte Carlo bankroll simulation for fractional-Kelly staking.
+//!
+//! `kelly_criterion` only gives a single optimal fraction; this module plays
+//! out many random bet sequences at that fraction (and at half/quarter Kelly)
+//! so the user can see what the fraction actually does to a bankroll over
+//! time: median growth, drawdown, and risk of ruin.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Number of independent bankroll paths simulated per strategy.
+const TRIALS: usize = 10_000;
+
+/// A trial counts as "ruin" once the bankroll drops below this fraction of
+/// the starting capital.
+const RUIN_THRESHOLD_FRACTION: f64 = 0.01;
+
+/// Result of simulating one fractional-Kelly strategy over many trials.
+pub struct SimulationSummary {
+    /// Label for the strategy, e.g. "Full Kelly"
+    pub label: String,
+    /// Median final bankroll across all trials
+    pub median_final: f64,
+    /// 5th percentile final bankroll
+    pub p5_final: f64,
+    /// 95th percentile final bankroll
+    pub p95_final: f64,
+    /// Mean geometric growth rate per round
+    pub mean_growth_rate: f64,
+    /// Largest drawdown observed across all trials (0-1)
+    pub max_drawdown: f64,
+    /// Fraction of trials that ever dropped below the ruin threshold
+    pub risk_of_ruin: f64,
+}
+
+/// Minimal xorshift64 PRNG so the simulation needs no external dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn new() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x9e3779b97f4a7c15)
+            | 1;
+        Rng(seed)
+    }
+
+    /// Next uniform value in [0, 1)
+    fn next_unit(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Play out a single bankroll path for `rounds` bets at stake fraction `f`.
+///
+/// Returns (final bankroll, minimum bankroll reached, whether it ever ruined).
+fn simulate_one_trial(
+    capital: f64,
+    rounds: u32,
+    f: f64,
+    b: f64,
+    win_rate: f64,
+    rng: &mut Rng,
+) -> (f64, f64, bool) {
+    let mut bankroll = capital;
+    let mut min_bankroll = capital;
+    let mut ruined = false;
+    let ruin_level = capital * RUIN_THRESHOLD_FRACTION;
+
+    for _ in 0..rounds {
+        if rng.next_unit() < win_rate {
+            bankroll *= 1.0 + f * b;
+        } else {
+            bankroll *= 1.0 - f;
+        }
+        if bankroll < min_bankroll {
+            min_bankroll = bankroll;
+        }
+        if bankroll < ruin_level {
+            ruined = true;
+        }
+    }
+
+    (bankroll, min_bankroll, ruined)
+}
+
+/// Value at percentile `p` (0-1) of an already-sorted slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let idx = ((sorted.len() as f64 - 1.0) * p).round() as usize;
+    sorted[idx]
+}
+
+/// Simulate `TRIALS` bankroll paths at `kelly_fraction * fraction_multiplier`.
+fn simulate(
+    label: &str,
+    odds: f64,
+    win_rate: f64,
+    capital: f64,
+    rounds: u32,
+    kelly_fraction: f64,
+    fraction_multiplier: f64,
+) -> SimulationSummary {
+    let f = kelly_fraction * fraction_multiplier;
+    let b = odds - 1.0;
+    let mut rng = Rng::new();
+
+    let mut finals = Vec::with_capacity(TRIALS);
+    let mut max_drawdown = 0.0_f64;
+    let mut growth_rate_sum = 0.0_f64;
+    let mut ruin_count = 0usize;
+
+    for _ in 0..TRIALS {
+        let (final_bankroll, min_bankroll, ruined) =
+            simulate_one_trial(capital, rounds, f, b, win_rate, &mut rng);
+        finals.push(final_bankroll);
+        max_drawdown = max_drawdown.max(1.0 - min_bankroll / capital);
+        growth_rate_sum += (final_bankroll / capital).ln() / rounds as f64;
+        if ruined {
+            ruin_count += 1;
+        }
+    }
+
+    finals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    SimulationSummary {
+        label: label.to_string(),
+        median_final: percentile(&finals, 0.50),
+        p5_final: percentile(&finals, 0.05),
+        p95_final: percentile(&finals, 0.95),
+        mean_growth_rate: growth_rate_sum / TRIALS as f64,
+        max_drawdown,
+        risk_of_ruin: ruin_count as f64 / TRIALS as f64,
+    }
+}
+
+/// Run the full/half/quarter Kelly comparison used by the CLI's simulate mode.
+pub fn simulate_all(
+    odds: f64,
+    win_rate: f64,
+    capital: f64,
+    rounds: u32,
+    kelly_fraction: f64,
+) -> Vec<SimulationSummary> {
+    vec![
+        simulate("Full Kelly", odds, win_rate, capital, rounds, kelly_fraction, 1.0),
+        simulate("Half Kelly", odds, win_rate, capital, rounds, kelly_fraction, 0.5),
+        simulate("Quarter Kelly", odds, win_rate, capital, rounds, kelly_fraction, 0.25),
+    ]
+}
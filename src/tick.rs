@@ -0,0 +1,86 @@
+//! Rounds computed stake amounts to a venue's tradable increments.
+//!
+//! Real exchanges and prediction markets only accept stakes in discrete
+//! increments, so this module snaps a raw Kelly stake to the nearest
+//! allowed amount: either a uniform tick size, or a table of ascending
+//! boundaries loaded from a file, using the same bucketed-rounding idea as
+//! a regulated price-fraction rescaling table.
+
+/// A tick policy: either a uniform increment or a boundary->value table.
+pub enum Tick {
+    /// Round to the nearest multiple of this size
+    Uniform(f64),
+    /// Sorted ascending (boundary, rounded_value) pairs
+    Table(Vec<(f64, f64)>),
+}
+
+impl Tick {
+    /// Parse a `--tick <value>` argument: a plain number is a uniform tick
+    /// size (an error unless positive), otherwise it's treated as a path to a
+    /// two-column `boundary,value` table file.
+    pub fn parse(value: &str) -> Result<Tick, String> {
+        if let Ok(size) = value.parse::<f64>() {
+            if size <= 0.0 {
+                return Err(format!("tick size must be positive, got {}", size));
+            }
+            return Ok(Tick::Uniform(size));
+        }
+
+        let contents = std::fs::read_to_string(value)
+            .map_err(|_| format!("'{}' is neither a tick size nor a readable tick table file", value))?;
+        Self::parse_table(&contents)
+    }
+
+    fn parse_table(contents: &str) -> Result<Tick, String> {
+        let mut table = Vec::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let cols: Vec<&str> = line.split(',').map(str::trim).collect();
+            if cols.len() != 2 {
+                return Err(format!("tick table line {}: expected 'boundary,value'", i + 1));
+            }
+            let boundary: f64 = cols[0]
+                .parse()
+                .map_err(|_| format!("tick table line {}: invalid boundary '{}'", i + 1, cols[0]))?;
+            let rounded_value: f64 = cols[1]
+                .parse()
+                .map_err(|_| format!("tick table line {}: invalid value '{}'", i + 1, cols[1]))?;
+            table.push((boundary, rounded_value));
+        }
+
+        if table.is_empty() {
+            return Err("tick table has no rows".to_string());
+        }
+        table.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        Ok(Tick::Table(table))
+    }
+
+    /// Snap `amount` to an allowable amount under this policy: the nearest
+    /// multiple of a uniform tick, or for a table, the value of the last
+    /// boundary at or below `amount` (the first row's value when `amount` is
+    /// below every boundary).
+    pub fn round(&self, amount: f64) -> f64 {
+        match self {
+            Tick::Uniform(size) => (amount / size).round() * size,
+            Tick::Table(table) => {
+                let idx = bisect_right(table, amount);
+                if idx == 0 {
+                    table[0].1
+                } else {
+                    table[idx - 1].1
+                }
+            }
+        }
+    }
+}
+
+/// Number of table boundaries at or below `amount` (bisect_right over the
+/// boundary column).
+fn bisect_right(table: &[(f64, f64)], amount: f64) -> usize {
+    table.partition_point(|&(boundary, _)| boundary <= amount)
+}